@@ -43,6 +43,7 @@ extern crate proc_macro;
 extern crate proc_macro2;
 #[macro_use]
 extern crate quote;
+#[macro_use]
 extern crate syn;
 
 use proc_macro::TokenStream;
@@ -87,7 +88,77 @@ fn dummy_const_trick<T: quote::ToTokens>(
     }
 }
 
-/// Derives [`num_traits::FromPrimitive`][from] for simple enums.
+// Shared scaffolding for every derive that delegates to a newtype struct's single field: find
+// the field, and read from / construct `#name` around it. Used by `FromPrimitive`/`ToPrimitive`
+// for their newtype-struct case, and by the `Zero`/`One`/`Num`/`NumCast`/`Bounded` derives,
+// which target nothing *but* newtype structs.
+enum NewtypeField {
+    Unnamed,
+    Named(Ident),
+}
+
+impl NewtypeField {
+    /// The tokens that follow `self.` to name this field.
+    fn access(&self) -> proc_macro2::TokenStream {
+        match *self {
+            NewtypeField::Unnamed => quote!(0),
+            NewtypeField::Named(ref ident) => quote!(#ident),
+        }
+    }
+
+    /// Builds a `#name { .. }` or `#name(..)` expression wrapping `inner`.
+    fn construct(&self, name: &Ident, inner: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match *self {
+            NewtypeField::Unnamed => quote! { #name(#inner) },
+            NewtypeField::Named(ref ident) => quote! { #name { #ident: #inner } },
+        }
+    }
+}
+
+/// Locates the single field of a newtype struct (tuple or named), or panics naming `trait_` as
+/// the derive that required it.
+fn newtype_field(
+    trait_: &str,
+    name: &Ident,
+    data_struct: &syn::DataStruct,
+) -> (NewtypeField, syn::Type) {
+    match data_struct.fields {
+        Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => (
+            NewtypeField::Unnamed,
+            fields.unnamed.iter().next().unwrap().ty.clone(),
+        ),
+        Fields::Named(ref fields) if fields.named.len() == 1 => {
+            let field = fields.named.iter().next().unwrap();
+            (
+                NewtypeField::Named(field.ident.clone().unwrap()),
+                field.ty.clone(),
+            )
+        }
+        _ => panic!(
+            "`{}` can be applied only to newtype structs with exactly one field, \
+             {} is not a newtype struct",
+            trait_, name
+        ),
+    }
+}
+
+/// Clones `ast.generics` and adds a `where #field_ty: #bound` predicate, so only the delegated
+/// field's type is required to implement the trait being derived, not every generic parameter
+/// on `#name`.
+fn generics_for_delegate(
+    ast: &syn::DeriveInput,
+    field_ty: &syn::Type,
+    bound: proc_macro2::TokenStream,
+) -> syn::Generics {
+    let mut generics = ast.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#field_ty: #bound));
+    generics
+}
+
+/// Derives [`num_traits::FromPrimitive`][from] for simple enums and newtype structs.
 ///
 /// [from]: https://docs.rs/num-traits/0.2/num_traits/cast/trait.FromPrimitive.html
 ///
@@ -122,7 +193,7 @@ fn dummy_const_trick<T: quote::ToTokens>(
 /// # fn main() {}
 /// ```
 ///
-/// Structs are not allowed:
+/// Structs with more than one field are not allowed:
 ///
 /// ```compile_fail
 /// # #[macro_use]
@@ -135,67 +206,371 @@ fn dummy_const_trick<T: quote::ToTokens>(
 /// }
 /// # fn main() {}
 /// ```
-#[proc_macro_derive(FromPrimitive)]
+///
+/// Newtype structs delegate to their single field:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+///
+/// #[derive(FromPrimitive)]
+/// struct Meters(u32);
+/// # fn main() {}
+/// ```
+///
+/// An enum may mix unit variants with a single data-carrying variant, as long as that variant
+/// is marked `#[num(delegate)]` to nominate it as the catch-all for values that don't match any
+/// unit variant's discriminant:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+///
+/// #[derive(FromPrimitive)]
+/// #[repr(u16)]
+/// enum Mix {
+///     Zero = 0,
+///     One = 1,
+///     #[num(delegate)]
+///     Other(u16),
+/// }
+/// # fn main() {}
+/// ```
+///
+/// `#[repr(...)]` is honored, so discriminants that don't fit in an `i64` still round-trip:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+///
+/// #[derive(FromPrimitive)]
+/// #[repr(u64)]
+/// enum Big {
+///     Zero = 0,
+///     Huge = 0xffff_ffff_ffff_ffff,
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Newtype structs may be generic over their delegated field:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+///
+/// #[derive(FromPrimitive)]
+/// struct Wrapper<T>(T);
+/// # fn main() {}
+/// ```
+#[proc_macro_derive(FromPrimitive, attributes(num))]
 pub fn from_primitive(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
-    let name = &ast.ident;
 
-    let variants = match ast.data {
-        Data::Enum(ref data_enum) => &data_enum.variants,
+    match ast.data {
+        Data::Enum(ref data_enum) => from_primitive_enum(&ast, data_enum),
+        Data::Struct(ref data_struct) => from_primitive_newtype(&ast, data_struct),
         _ => panic!(
-            "`FromPrimitive` can be applied only to the enums, {} is not an enum",
-            name
+            "`FromPrimitive` can be applied only to the enums and newtype structs, {} is neither",
+            ast.ident
         ),
-    };
+    }
+}
+
+/// Returns `true` if the variant is annotated with `#[num(delegate)]`, which nominates it to
+/// receive the delegated conversion when no unit variant's discriminant matches.
+fn is_delegate_variant(variant: &syn::Variant) -> bool {
+    variant.attrs.iter().any(|attr| {
+        if !attr.path.is_ident("num") {
+            return false;
+        }
+        match attr.parse_meta() {
+            Ok(syn::Meta::List(ref list)) => list.nested.iter().any(|nested| match *nested {
+                syn::NestedMeta::Meta(syn::Meta::Word(ref ident)) => ident == "delegate",
+                _ => false,
+            }),
+            _ => false,
+        }
+    })
+}
+
+/// The integer type named by a `#[repr(...)]` attribute, i.e. the type the compiler actually
+/// uses to store the enum's discriminant.
+#[derive(Clone, Copy)]
+enum IntRepr {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    Isize,
+    Usize,
+}
+
+impl IntRepr {
+    fn from_str(s: &str) -> Option<IntRepr> {
+        match s {
+            "i8" => Some(IntRepr::I8),
+            "u8" => Some(IntRepr::U8),
+            "i16" => Some(IntRepr::I16),
+            "u16" => Some(IntRepr::U16),
+            "i32" => Some(IntRepr::I32),
+            "u32" => Some(IntRepr::U32),
+            "i64" => Some(IntRepr::I64),
+            "u64" => Some(IntRepr::U64),
+            "isize" => Some(IntRepr::Isize),
+            "usize" => Some(IntRepr::Usize),
+            _ => None,
+        }
+    }
+
+    /// The `from_*`/`to_*` method pair that matches this representation's width and
+    /// signedness exactly, along with its Rust type and whether that pair is `from_u64`/
+    /// `to_u64` (which, unlike the narrower exact methods, replaces rather than supplements
+    /// the default `from_i64`/`to_i64`-forwarding body). `None` for `i64`/`isize`, since those
+    /// already round-trip losslessly through the `from_i64`/`to_i64` methods generated
+    /// unconditionally below.
+    fn exact_methods(self) -> Option<(Ident, Ident, proc_macro2::TokenStream, bool)> {
+        let (from, to, ty, is_u64) = match self {
+            IntRepr::I8 => ("from_i8", "to_i8", quote!(i8), false),
+            IntRepr::U8 => ("from_u8", "to_u8", quote!(u8), false),
+            IntRepr::I16 => ("from_i16", "to_i16", quote!(i16), false),
+            IntRepr::U16 => ("from_u16", "to_u16", quote!(u16), false),
+            IntRepr::I32 => ("from_i32", "to_i32", quote!(i32), false),
+            IntRepr::U32 => ("from_u32", "to_u32", quote!(u32), false),
+            IntRepr::I64 | IntRepr::Isize => return None,
+            IntRepr::U64 | IntRepr::Usize => ("from_u64", "to_u64", quote!(u64), true),
+        };
+        Some((
+            Ident::new(from, Span::call_site()),
+            Ident::new(to, Span::call_site()),
+            ty,
+            is_u64,
+        ))
+    }
+}
+
+/// Parses the integer carrier type named by a `#[repr(...)]` attribute, if any.
+fn repr_type(attrs: &[syn::Attribute]) -> Option<IntRepr> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(syn::Meta::List(ref list)) => list.nested.iter().find_map(|nested| match *nested {
+                syn::NestedMeta::Meta(syn::Meta::Word(ref ident)) => {
+                    IntRepr::from_str(&ident.to_string())
+                }
+                _ => None,
+            }),
+            _ => None,
+        })
+        .next()
+}
+
+// `EnumName::Variant as i64` only compiles when the *whole* enum is fieldless (E0605), so once
+// a single variant carries data we can no longer cast any of its sibling unit variants either.
+// In that case we fall back to computing each unit variant's discriminant ourselves and
+// splicing it in as a literal, following the same numbering Rust itself uses (start at 0,
+// +1 per variant, reset by an explicit `= N`).
+fn discriminant_literal(expr: &syn::Expr) -> Option<i128> {
+    match *expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(ref lit), .. }) => Some(lit.value() as i128),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr: ref inner, .. }) => {
+            discriminant_literal(inner).map(|v| -v)
+        }
+        _ => None,
+    }
+}
+
+fn discriminants(name: &Ident, data_enum: &syn::DataEnum) -> Vec<i128> {
+    let mut next = 0i128;
+    data_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let value = match variant.discriminant {
+                Some((_, ref expr)) => discriminant_literal(expr).unwrap_or_else(|| {
+                    panic!(
+                        "`FromPrimitive`/`ToPrimitive` can only derive {} because it mixes in a \
+                         data-carrying variant if every discriminant is a literal integer; \
+                         {}::{}'s is not",
+                        name, name, variant.ident
+                    )
+                }),
+                None => next,
+            };
+            next = value + 1;
+            value
+        })
+        .collect()
+}
+
+fn from_primitive_enum(
+    ast: &syn::DeriveInput,
+    data_enum: &syn::DataEnum,
+) -> TokenStream {
+    let name = &ast.ident;
+    let variants = &data_enum.variants;
+    let mixed = variants.iter().any(|variant| !matches!(variant.fields, Fields::Unit));
+    let discriminants = if mixed { Some(discriminants(name, data_enum)) } else { None };
 
     let from_i64_var = quote! { n };
+    let mut delegate_ident = None;
     let clauses: Vec<_> = variants
         .iter()
-        .map(|variant| {
+        .enumerate()
+        .filter_map(|(i, variant)| {
             let ident = &variant.ident;
             match variant.fields {
-                Fields::Unit => (),
+                Fields::Unit => {
+                    let disc = match discriminants {
+                        Some(ref discriminants) => {
+                            let value = discriminants[i] as i64;
+                            quote!(#value)
+                        }
+                        None => quote!(#name::#ident as i64),
+                    };
+                    Some(quote! {
+                        if #from_i64_var == #disc {
+                            Some(#name::#ident)
+                        }
+                    })
+                }
+                Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 && is_delegate_variant(variant) => {
+                    if delegate_ident.is_some() {
+                        panic!(
+                            "`FromPrimitive` allows at most one `#[num(delegate)]` variant, \
+                             {} has more than one",
+                            name
+                        );
+                    }
+                    delegate_ident = Some(ident.clone());
+                    None
+                }
                 _ => panic!(
-                    "`FromPrimitive` can be applied only to unitary enums, \
-                     {}::{} is either struct or tuple",
+                    "`FromPrimitive` can be applied only to unitary enums, or enums whose \
+                     data-carrying variants are marked `#[num(delegate)]`, \
+                     {}::{} is neither",
                     name, ident
                 ),
             }
-
-            quote! {
-                if #from_i64_var == #name::#ident as i64 {
-                    Some(#name::#ident)
-                }
-            }
         })
         .collect();
 
-    let from_i64_var = if clauses.is_empty() {
+    let from_i64_var = if clauses.is_empty() && delegate_ident.is_none() {
         quote!(_)
     } else {
         from_i64_var
     };
 
+    let fallback = match delegate_ident {
+        Some(ref ident) => quote! {
+            _num_traits::FromPrimitive::from_i64(#from_i64_var).map(#name::#ident)
+        },
+        None => quote! { None },
+    };
+
+    // A data-carrying variant can't be cast through the repr's native width, so honoring
+    // `#[repr(...)]` only applies to plain, unitary enums.
+    let exact = if delegate_ident.is_none() {
+        repr_type(&ast.attrs).and_then(IntRepr::exact_methods)
+    } else {
+        None
+    };
+
+    let exact_clauses = |ty: &proc_macro2::TokenStream| -> Vec<_> {
+        variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                quote! {
+                    if n == #name::#ident as #ty {
+                        Some(#name::#ident)
+                    }
+                }
+            })
+            .collect()
+    };
+
+    // `u64`/`usize` reprs can exceed `i64::MAX`, so `from_u64` needs an exact comparison
+    // instead of forwarding through `from_i64` (which would misround such values).
+    let from_u64_body = match exact {
+        Some((_, _, ref ty, true)) => {
+            let clauses = exact_clauses(ty);
+            quote! { #(#clauses else)* { None } }
+        }
+        _ => quote! { Self::from_i64(n as i64) },
+    };
+
+    // Narrower reprs (`u8`, `i16`, ...) round-trip fine through `i64`/`u64`, but we still emit
+    // the exact-width method so no widening/narrowing cast is ever applied to them.
+    let extra_from_method = match exact {
+        Some((ref from_method, _, ref ty, false)) => {
+            let clauses = exact_clauses(ty);
+            quote! {
+                fn #from_method(n: #ty) -> Option<Self> {
+                    #(#clauses else)* { None }
+                }
+            }
+        }
+        _ => quote!(),
+    };
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
     dummy_const_trick("FromPrimative", &name, quote! {
         #[allow(unused_qualifications)]
         extern crate num_traits as _num_traits;
 
-        impl _num_traits::FromPrimitive for #name {
+        impl #impl_generics _num_traits::FromPrimitive for #name #ty_generics #where_clause {
             #[allow(trivial_numeric_casts)]
             fn from_i64(#from_i64_var: i64) -> Option<Self> {
                 #(#clauses else)* {
-                    None
+                    #fallback
                 }
             }
 
             fn from_u64(n: u64) -> Option<Self> {
-                Self::from_i64(n as i64)
+                #from_u64_body
+            }
+
+            #extra_from_method
+        }
+    }).into()
+}
+
+fn from_primitive_newtype(
+    ast: &syn::DeriveInput,
+    data_struct: &syn::DataStruct,
+) -> TokenStream {
+    let name = &ast.ident;
+    let (field, field_ty) = newtype_field("FromPrimitive", name, data_struct);
+    let construct = field.construct(name, quote!(inner));
+
+    // Only require the inner field to implement `FromPrimitive` when we actually delegate to
+    // it, so unrelated generic parameters on `#name` aren't forced to satisfy the bound too.
+    let generics = generics_for_delegate(ast, &field_ty, quote!(_num_traits::FromPrimitive));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    dummy_const_trick("FromPrimative", &name, quote! {
+        #[allow(unused_qualifications)]
+        extern crate num_traits as _num_traits;
+
+        impl #impl_generics _num_traits::FromPrimitive for #name #ty_generics #where_clause {
+            fn from_i64(n: i64) -> Option<Self> {
+                _num_traits::FromPrimitive::from_i64(n).map(|inner| #construct)
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                _num_traits::FromPrimitive::from_u64(n).map(|inner| #construct)
             }
         }
     }).into()
 }
 
-/// Derives [`num_traits::ToPrimitive`][to] for simple enums.
+/// Derives [`num_traits::ToPrimitive`][to] for simple enums and newtype structs.
 ///
 /// [to]: https://docs.rs/num-traits/0.2/num_traits/cast/trait.ToPrimitive.html
 ///
@@ -230,7 +605,7 @@ pub fn from_primitive(input: TokenStream) -> TokenStream {
 /// # fn main() {}
 /// ```
 ///
-/// Structs are not allowed:
+/// Structs with more than one field are not allowed:
 ///
 /// ```compile_fail
 /// # #[macro_use]
@@ -243,34 +618,101 @@ pub fn from_primitive(input: TokenStream) -> TokenStream {
 /// }
 /// # fn main() {}
 /// ```
+///
+/// Newtype structs delegate to their single field:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+///
+/// #[derive(ToPrimitive)]
+/// struct Meters(u32);
+/// # fn main() {}
+/// ```
+///
+/// An enum may mix unit variants with variants that carry a single value; each data-carrying
+/// variant forwards to its inner value's own `ToPrimitive` impl:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+///
+/// #[derive(ToPrimitive)]
+/// #[repr(u16)]
+/// enum Mix {
+///     Zero = 0,
+///     One = 1,
+///     Other(u16),
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Newtype structs may be generic over their delegated field:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+///
+/// #[derive(ToPrimitive)]
+/// struct Wrapper<T>(T);
+/// # fn main() {}
+/// ```
 #[proc_macro_derive(ToPrimitive)]
 pub fn to_primitive(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
-    let name = &ast.ident;
 
-    let variants = match ast.data {
-        Data::Enum(ref data_enum) => &data_enum.variants,
+    match ast.data {
+        Data::Enum(ref data_enum) => to_primitive_enum(&ast, data_enum),
+        Data::Struct(ref data_struct) => to_primitive_newtype(&ast, data_struct),
         _ => panic!(
-            "`ToPrimitive` can be applied only to the enums, {} is not an enum",
-            name
+            "`ToPrimitive` can be applied only to the enums and newtype structs, {} is neither",
+            ast.ident
         ),
-    };
+    }
+}
 
-    let variants: Vec<_> = variants
+fn to_primitive_enum(
+    ast: &syn::DeriveInput,
+    data_enum: &syn::DataEnum,
+) -> TokenStream {
+    let name = &ast.ident;
+    let has_data_variant = data_enum
+        .variants
         .iter()
-        .map(|variant| {
+        .any(|variant| !matches!(variant.fields, Fields::Unit));
+    let discriminants = if has_data_variant {
+        Some(discriminants(name, data_enum))
+    } else {
+        None
+    };
+    let variants: Vec<_> = data_enum.variants
+        .iter()
+        .enumerate()
+        .map(|(i, variant)| {
             let ident = &variant.ident;
             match variant.fields {
-                Fields::Unit => (),
+                // NB: We have to check each variant individually, because we'll only have
+                // `&self` for the input.  We can't move from that, and it might not be `Clone`
+                // or `Copy`.  (Otherwise we could just do `*self as i64` without a `match` at
+                // all.)
+                Fields::Unit => {
+                    let disc = match discriminants {
+                        Some(ref discriminants) => {
+                            let value = discriminants[i] as i64;
+                            quote!(#value)
+                        }
+                        None => quote!(#name::#ident as i64),
+                    };
+                    quote!(#name::#ident => Some(#disc))
+                }
+                Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                    quote!(#name::#ident(ref inner) => _num_traits::ToPrimitive::to_i64(inner))
+                }
                 _ => {
-                    panic!("`ToPrimitive` can be applied only to unitary enums, {}::{} is either struct or tuple", name, ident)
+                    panic!("`ToPrimitive` can be applied only to unitary enums, or enums whose \
+                            data-carrying variants wrap a single field, {}::{} is neither", name, ident)
                 },
             }
-
-            // NB: We have to check each variant individually, because we'll only have `&self`
-            // for the input.  We can't move from that, and it might not be `Clone` or `Copy`.
-            // (Otherwise we could just do `*self as i64` without a `match` at all.)
-            quote!(#name::#ident => #name::#ident as i64)
         })
         .collect();
 
@@ -281,25 +723,346 @@ pub fn to_primitive(input: TokenStream) -> TokenStream {
         }
     } else {
         quote! {
-            Some(match *self {
+            match *self {
                 #(#variants,)*
+            }
+        }
+    };
+
+    // A data-carrying variant can't be cast through the repr's native width, so honoring
+    // `#[repr(...)]` only applies to plain, unitary enums.
+    let exact = if has_data_variant {
+        None
+    } else {
+        repr_type(&ast.attrs).and_then(IntRepr::exact_methods)
+    };
+
+    let exact_arms = |ty: &proc_macro2::TokenStream| -> Vec<_> {
+        data_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                quote!(#name::#ident => #name::#ident as #ty)
             })
+            .collect()
+    };
+
+    // `u64`/`usize` reprs can exceed `i64::MAX`, so `to_u64` needs its own match instead of
+    // forwarding through `to_i64` (which would misround such values).
+    let to_u64_body = match exact {
+        Some((_, _, ref ty, true)) => {
+            let arms = exact_arms(ty);
+            quote! { Some(match *self { #(#arms,)* }) }
+        }
+        _ => quote! { self.to_i64().map(|x| x as u64) },
+    };
+
+    // Narrower reprs (`u8`, `i16`, ...) round-trip fine through `i64`/`u64`, but we still emit
+    // the exact-width method so no widening/narrowing cast is ever applied to them.
+    let extra_to_method = match exact {
+        Some((_, ref to_method, ref ty, false)) => {
+            let arms = exact_arms(ty);
+            quote! {
+                fn #to_method(&self) -> Option<#ty> {
+                    Some(match *self { #(#arms,)* })
+                }
+            }
         }
+        _ => quote!(),
     };
 
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
     dummy_const_trick("ToPrimative", &name, quote! {
         #[allow(unused_qualifications)]
         extern crate num_traits as _num_traits;
 
-        impl _num_traits::ToPrimitive for #name {
+        impl #impl_generics _num_traits::ToPrimitive for #name #ty_generics #where_clause {
             #[allow(trivial_numeric_casts)]
             fn to_i64(&self) -> Option<i64> {
                 #match_expr
             }
 
             fn to_u64(&self) -> Option<u64> {
-                self.to_i64().map(|x| x as u64)
+                #to_u64_body
+            }
+
+            #extra_to_method
+        }
+    }).into()
+}
+
+fn to_primitive_newtype(
+    ast: &syn::DeriveInput,
+    data_struct: &syn::DataStruct,
+) -> TokenStream {
+    let name = &ast.ident;
+    let (field, field_ty) = newtype_field("ToPrimitive", name, data_struct);
+    let access = field.access();
+
+    // Only require the inner field to implement `ToPrimitive` when we actually delegate to it,
+    // so unrelated generic parameters on `#name` aren't forced to satisfy the bound too.
+    let generics = generics_for_delegate(ast, &field_ty, quote!(_num_traits::ToPrimitive));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    dummy_const_trick("ToPrimative", &name, quote! {
+        #[allow(unused_qualifications)]
+        extern crate num_traits as _num_traits;
+
+        impl #impl_generics _num_traits::ToPrimitive for #name #ty_generics #where_clause {
+            fn to_i64(&self) -> Option<i64> {
+                _num_traits::ToPrimitive::to_i64(&self.#access)
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                _num_traits::ToPrimitive::to_u64(&self.#access)
             }
         }
     }).into()
 }
+
+// Shared scaffolding for every derive that targets newtype structs only (`Zero`, `One`, `Num`,
+// `NumCast`, `Bounded`): parse the input, locate its single field, thread that field's type
+// through the struct's own generics via `bound`, and wrap the trait impl `body` builds in the
+// dummy-const trick. `bound` doubles as the trait path in the generated `impl ... for` clause,
+// since it's always the same path already needed for the delegation bound.
+fn derive_newtype_delegate(
+    trait_: &str,
+    input: TokenStream,
+    bound: proc_macro2::TokenStream,
+    body: impl FnOnce(&Ident, &NewtypeField, &syn::Type) -> proc_macro2::TokenStream,
+) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let name = &ast.ident;
+
+    let data_struct = match ast.data {
+        Data::Struct(ref data_struct) => data_struct,
+        _ => panic!("`{}` can be applied only to newtype structs, {} is not a struct", trait_, name),
+    };
+
+    let (field, field_ty) = newtype_field(trait_, name, data_struct);
+    let generics = generics_for_delegate(&ast, &field_ty, bound.clone());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let items = body(name, &field, &field_ty);
+
+    dummy_const_trick(trait_, &name, quote! {
+        #[allow(unused_qualifications)]
+        extern crate num_traits as _num_traits;
+
+        impl #impl_generics #bound for #name #ty_generics #where_clause {
+            #items
+        }
+    }).into()
+}
+
+/// Derives [`num_traits::Zero`][zero] for newtype structs by delegating to the wrapped field.
+///
+/// [zero]: https://docs.rs/num-traits/0.2/num_traits/identities/trait.Zero.html
+///
+/// # Examples
+///
+/// `Zero`'s supertrait bound requires `Meters: Add<Meters, Output = Meters>`, so deriving it
+/// also means implementing (or deriving) `Add`:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+/// use std::ops::Add;
+///
+/// #[derive(Zero, PartialEq, Debug)]
+/// struct Meters(f64);
+///
+/// impl Add for Meters {
+///     type Output = Meters;
+///     fn add(self, other: Meters) -> Meters {
+///         Meters(self.0 + other.0)
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[proc_macro_derive(Zero)]
+pub fn zero(input: TokenStream) -> TokenStream {
+    derive_newtype_delegate("Zero", input, quote!(_num_traits::Zero), |name, field, _field_ty| {
+        let access = field.access();
+        let construct = field.construct(name, quote!(_num_traits::Zero::zero()));
+        quote! {
+            fn zero() -> Self {
+                #construct
+            }
+
+            fn is_zero(&self) -> bool {
+                _num_traits::Zero::is_zero(&self.#access)
+            }
+        }
+    })
+}
+
+/// Derives [`num_traits::One`][one] for newtype structs by delegating to the wrapped field.
+///
+/// [one]: https://docs.rs/num-traits/0.2/num_traits/identities/trait.One.html
+///
+/// # Examples
+///
+/// `One`'s supertrait bound requires `Meters: Mul<Meters, Output = Meters>`, so deriving it
+/// also means implementing (or deriving) `Mul`:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+/// use std::ops::Mul;
+///
+/// #[derive(One, PartialEq, Debug)]
+/// struct Meters(f64);
+///
+/// impl Mul for Meters {
+///     type Output = Meters;
+///     fn mul(self, other: Meters) -> Meters {
+///         Meters(self.0 * other.0)
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[proc_macro_derive(One)]
+pub fn one(input: TokenStream) -> TokenStream {
+    derive_newtype_delegate("One", input, quote!(_num_traits::One), |name, field, _field_ty| {
+        let construct = field.construct(name, quote!(_num_traits::One::one()));
+        quote! {
+            fn one() -> Self {
+                #construct
+            }
+        }
+    })
+}
+
+/// Derives [`num_traits::Num`][num] for newtype structs by delegating to the wrapped field.
+///
+/// [num]: https://docs.rs/num-traits/0.2/num_traits/trait.Num.html
+///
+/// # Examples
+///
+/// `Num`'s supertrait bounds require `Meters: PartialEq + Zero + One + NumOps`, so deriving it
+/// also means deriving (or implementing) `PartialEq`, `Zero`, `One`, and the arithmetic
+/// operators that make up `NumOps` (`Add`, `Sub`, `Mul`, `Div`, `Rem`):
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+/// use std::ops::{Add, Div, Mul, Rem, Sub};
+///
+/// #[derive(Num, Zero, One, PartialEq, Debug)]
+/// struct Meters(f64);
+///
+/// impl Add for Meters {
+///     type Output = Meters;
+///     fn add(self, other: Meters) -> Meters {
+///         Meters(self.0 + other.0)
+///     }
+/// }
+///
+/// impl Sub for Meters {
+///     type Output = Meters;
+///     fn sub(self, other: Meters) -> Meters {
+///         Meters(self.0 - other.0)
+///     }
+/// }
+///
+/// impl Mul for Meters {
+///     type Output = Meters;
+///     fn mul(self, other: Meters) -> Meters {
+///         Meters(self.0 * other.0)
+///     }
+/// }
+///
+/// impl Div for Meters {
+///     type Output = Meters;
+///     fn div(self, other: Meters) -> Meters {
+///         Meters(self.0 / other.0)
+///     }
+/// }
+///
+/// impl Rem for Meters {
+///     type Output = Meters;
+///     fn rem(self, other: Meters) -> Meters {
+///         Meters(self.0 % other.0)
+///     }
+/// }
+/// # fn main() {}
+/// ```
+#[proc_macro_derive(Num)]
+pub fn num(input: TokenStream) -> TokenStream {
+    derive_newtype_delegate("Num", input, quote!(_num_traits::Num), |name, field, field_ty| {
+        let construct = field.construct(name, quote!(inner));
+        quote! {
+            type FromStrRadixErr = <#field_ty as _num_traits::Num>::FromStrRadixErr;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                _num_traits::Num::from_str_radix(str, radix).map(|inner| #construct)
+            }
+        }
+    })
+}
+
+/// Derives [`num_traits::NumCast`][numcast] for newtype structs by delegating to the wrapped
+/// field.
+///
+/// [numcast]: https://docs.rs/num-traits/0.2/num_traits/cast/trait.NumCast.html
+///
+/// # Examples
+///
+/// `NumCast`'s supertrait bound requires `Meters: ToPrimitive`, which `#[derive(ToPrimitive)]`
+/// takes care of:
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+///
+/// #[derive(NumCast, ToPrimitive)]
+/// struct Meters(f64);
+/// # fn main() {}
+/// ```
+#[proc_macro_derive(NumCast)]
+pub fn num_cast(input: TokenStream) -> TokenStream {
+    derive_newtype_delegate("NumCast", input, quote!(_num_traits::NumCast), |name, field, _field_ty| {
+        let construct = field.construct(name, quote!(inner));
+        quote! {
+            // Named `__T` rather than `T` so it can't collide with a type parameter of the same
+            // name on `#name` itself (e.g. `struct Wrapper<T>(T)`).
+            fn from<__T: _num_traits::ToPrimitive>(n: __T) -> Option<Self> {
+                _num_traits::NumCast::from(n).map(|inner| #construct)
+            }
+        }
+    })
+}
+
+/// Derives [`num_traits::Bounded`][bounded] for newtype structs by delegating to the wrapped
+/// field.
+///
+/// [bounded]: https://docs.rs/num-traits/0.2/num_traits/bounds/trait.Bounded.html
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use]
+/// # extern crate num_derive;
+///
+/// #[derive(Bounded)]
+/// struct Meters(f64);
+/// # fn main() {}
+/// ```
+#[proc_macro_derive(Bounded)]
+pub fn bounded(input: TokenStream) -> TokenStream {
+    derive_newtype_delegate("Bounded", input, quote!(_num_traits::Bounded), |name, field, _field_ty| {
+        let min_construct = field.construct(name, quote!(_num_traits::Bounded::min_value()));
+        let max_construct = field.construct(name, quote!(_num_traits::Bounded::max_value()));
+        quote! {
+            fn min_value() -> Self {
+                #min_construct
+            }
+
+            fn max_value() -> Self {
+                #max_construct
+            }
+        }
+    })
+}
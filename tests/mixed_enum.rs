@@ -0,0 +1,29 @@
+#[macro_use]
+extern crate num_derive;
+extern crate num_traits;
+
+use num_traits::{FromPrimitive, ToPrimitive};
+
+#[derive(Debug, PartialEq, FromPrimitive, ToPrimitive)]
+#[repr(u16)]
+enum Mix {
+    Zero = 0,
+    One = 1,
+    #[num(delegate)]
+    Other(u16),
+}
+
+#[test]
+fn round_trips_unit_variants() {
+    assert_eq!(Mix::from_i64(0), Some(Mix::Zero));
+    assert_eq!(Mix::from_i64(1), Some(Mix::One));
+    assert_eq!(Mix::Zero.to_i64(), Some(0));
+    assert_eq!(Mix::One.to_i64(), Some(1));
+}
+
+#[test]
+fn round_trips_delegate_variant() {
+    assert_eq!(Mix::from_i64(42), Some(Mix::Other(42)));
+    assert_eq!(Mix::Other(42).to_i64(), Some(42));
+    assert_eq!(Mix::from_i64(-1), None);
+}